@@ -1,10 +1,11 @@
 use std::fs;
-use std::io::{ self, Read };
+use std::io::{ self, Cursor, Read };
 use std::str::FromStr;
 
 use braille::{ BRAILLE, BOX };
 use clap::{ clap_app, Error, ErrorKind };
-use image::{ DynamicImage, GenericImageView, imageops, Rgba };
+use image::{ DynamicImage, GenericImageView, RgbaImage, imageops, Rgba };
+use image::codecs::hdr::HdrDecoder;
 
 const RED_LUM: f32   = 0.299f32;
 const GREEN_LUM: f32 = 0.587f32;
@@ -13,6 +14,43 @@ const THRESHOLD: u8  = 96u8;
 
 const ASCII_CHARS: [char; 8] = [' ', '.', ',', '-', '/', 'O', '#', '@'];
 
+const RESET_SGR: &str = "\x1b[0m";
+
+const CELL_WIDTH: u32  = 4;
+const CELL_HEIGHT: u32 = 8;
+const DEFAULT_INK: (u8, u8, u8) = (255, 255, 255);
+const DEFAULT_BACKGROUND: (u8, u8, u8) = (0, 0, 0);
+
+const BAYER_4X4: [[u8; 4]; 4] = [
+	[ 0,  8,  2, 10],
+	[12,  4, 14,  6],
+	[ 3, 11,  1,  9],
+	[15,  7, 13,  5],
+];
+
+#[derive(Clone)]
+struct DotPattern {
+	cols: u32,
+	rows: u32,
+	dots: Vec<bool>,
+}
+
+struct Cell {
+	ch: char,
+	brightness: u8,
+	color: Option<(u8, u8, u8)>,
+	dots: Option<DotPattern>,
+}
+
+type Grid = Vec<Vec<Cell>>;
+
+struct ImageBuf<'a> {
+	image: &'a DynamicImage,
+	luminance: &'a [u8],
+	width: u32,
+	height: u32,
+}
+
 fn main() {
 	let matches = clap_app!(app =>
 		(name: env!("CARGO_PKG_NAME"))
@@ -27,6 +65,15 @@ fn main() {
 		(@arg double: -d --("double-width") "Write characters twice")
 		(@arg braille: -b --braille "Use braille instead of ASCII")
 		(@arg blocks: -B --blocks conflicts_with[braille] "Use blocks instead of ASCII")
+		(@arg color: -c --color "Colour output using the source pixels' RGB values")
+		(@arg dither: --dither "Floyd\u{2013}Steinberg dither before thresholding (braille/blocks)")
+		(@arg charset: --charset +takes_value "Specify a dark-to-light ASCII ramp to use instead of the default")
+		(@arg gamma: --gamma +takes_value "Specify a gamma correction applied to brightness before ramp lookup")
+		(@arg invert: -i --invert "Reverse the ASCII ramp, for dark-on-light terminals")
+		(@arg render: --render +takes_value "Render the output to a raster image file (format from extension)")
+		(@arg background: --background +takes_value "Specify the --render background colour as a RRGGBB hex triple")
+		(@arg white_point: --("white-point") +takes_value "Specify the white point for extended Reinhard tone mapping of HDR input")
+		(@arg aspect: --aspect +takes_value "Specify the width:height ratio of a terminal cell, for ASCII output (default 0.5)")
 	).get_matches();
 
 	let input = matches.value_of("INPUT").unwrap();
@@ -49,15 +96,42 @@ fn main() {
 	};
 
 	let input_data = input_data.as_slice();
-	let mut image = match image::load_from_memory(input_data) {
-		Ok(image) => image,
-		Err(error) => {
-			Error {
-				kind: ErrorKind::Io,
-				message: error.to_string(),
+
+	let white_point = if let Some(white_point) = matches.value_of("white_point") {
+		match f32::from_str(white_point) {
+			Ok(float) => Some(float),
+			Err(_) => Error {
+				kind: ErrorKind::InvalidValue,
+				message: "Value for white-point is not a valid number".into(),
 				info: None,
-			}.exit()
-		},
+			}.exit(),
+		}
+	} else {
+		None
+	};
+
+	let mut image = if is_radiance_hdr(input_data) {
+		match decode_radiance_hdr(input_data, white_point) {
+			Ok(image) => image,
+			Err(error) => {
+				Error {
+					kind: ErrorKind::Io,
+					message: error.to_string(),
+					info: None,
+				}.exit()
+			},
+		}
+	} else {
+		match image::load_from_memory(input_data) {
+			Ok(image) => image,
+			Err(error) => {
+				Error {
+					kind: ErrorKind::Io,
+					message: error.to_string(),
+					info: None,
+				}.exit()
+			},
+		}
 	};
 
 	if let Some(size) = matches.value_of("size") {
@@ -88,107 +162,465 @@ fn main() {
 	} else {
 		THRESHOLD
 	};
-	
+
+	let gamma = if let Some(gamma) = matches.value_of("gamma") {
+		match f32::from_str(gamma) {
+			Ok(float) if float > 0f32 => float,
+			_ => Error {
+				kind: ErrorKind::InvalidValue,
+				message: "Value for gamma is not a valid positive number".into(),
+				info: None,
+			}.exit(),
+		}
+	} else {
+		1.0f32
+	};
+
+	let mut charset: Vec<char> = matches.value_of("charset")
+		.map(|cs| cs.chars().collect())
+		.unwrap_or_else(|| ASCII_CHARS.into());
+
+	if charset.is_empty() {
+		Error {
+			kind: ErrorKind::InvalidValue,
+			message: "Value for charset must not be empty".into(),
+			info: None,
+		}.exit()
+	}
+
+	if matches.is_present("invert") {
+		charset.reverse();
+	}
+
+	let background = if let Some(background) = matches.value_of("background") {
+		match parse_hex_color(background) {
+			Some(rgb) => rgb,
+			None => Error {
+				kind: ErrorKind::InvalidValue,
+				message: "Value for background is not a valid RRGGBB hex colour".into(),
+				info: None,
+			}.exit(),
+		}
+	} else {
+		DEFAULT_BACKGROUND
+	};
+
+	let aspect = if let Some(aspect) = matches.value_of("aspect") {
+		match f32::from_str(aspect) {
+			Ok(float) if float > 0f32 => float,
+			_ => Error {
+				kind: ErrorKind::InvalidValue,
+				message: "Value for aspect is not a valid positive number".into(),
+				info: None,
+			}.exit(),
+		}
+	} else {
+		0.5f32
+	};
+
 	let double = matches.is_present("double");
+	let color = matches.is_present("color");
+	let dither = matches.is_present("dither");
 
-	println!(
-		"{}",
-		if matches.is_present("braille") {
-			to_braille(image, thresh, double)
-		} else if matches.is_present("blocks") {
-			to_blocks(image, thresh, double)
-		} else {
-			to_ascii(image, double)
-		},
-	);
+	let (luminance, width, height) = luminance_buffer(&image);
+	let buf = ImageBuf { image: &image, luminance: &luminance, width, height };
+
+	let grid = if matches.is_present("braille") {
+		to_braille(&buf, thresh, double, color, dither)
+	} else if matches.is_present("blocks") {
+		to_blocks(&buf, thresh, double, color, dither)
+	} else {
+		to_ascii(&buf, double, color, &charset, gamma, aspect)
+	};
+
+	println!("{}", render_terminal(&grid, color));
+
+	if let Some(path) = matches.value_of("render") {
+		if let Err(error) = render_raster(&grid, background).save(path) {
+			Error {
+				kind: ErrorKind::Io,
+				message: error.to_string(),
+				info: None,
+			}.exit()
+		}
+	}
+}
+
+fn parse_hex_color(s: &str) -> Option<(u8, u8, u8)> {
+	let s = s.strip_prefix('#').unwrap_or(s);
+	if !s.is_ascii() || s.len() != 6 { return None; }
+
+	let r = u8::from_str_radix(&s[0..2], 16).ok()?;
+	let g = u8::from_str_radix(&s[2..4], 16).ok()?;
+	let b = u8::from_str_radix(&s[4..6], 16).ok()?;
+
+	Some((r, g, b))
 }
 
 fn pixel_brightness(pixel: Rgba<u8>) -> u8 {
 	let lum = (RED_LUM * pixel[0] as f32)
 		+ (GREEN_LUM * pixel[1] as f32)
 		+ (BLUE_LUM * pixel[2] as f32);
-	
+
 	lum as u8
 }
 
-fn is_dark(image: DynamicImage, x: u32, y: u32, t: u8) -> usize {
-	let pixel = if image.in_bounds(x, y) {
+fn pixel_or_default(image: &DynamicImage, x: u32, y: u32) -> Rgba<u8> {
+	if image.in_bounds(x, y) {
 		image.get_pixel(x, y)
 	} else {
 		Rgba([ 255, 255, 255, 0 ])
+	}
+}
+
+// `image::load_from_memory` decodes Radiance HDR straight to 8-bit RGB, so
+// the float data is read directly via `HdrDecoder` instead.
+fn is_radiance_hdr(data: &[u8]) -> bool {
+	data.starts_with(image::codecs::hdr::SIGNATURE)
+}
+
+fn tone_map_pixel(r: f32, g: f32, b: f32, white_point: Option<f32>) -> image::Rgb<u8> {
+	let lum = (RED_LUM * r) + (GREEN_LUM * g) + (BLUE_LUM * b);
+	if lum <= 0f32 { return image::Rgb([ 0, 0, 0 ]); }
+
+	let mapped = match white_point {
+		Some(white) => lum * (1f32 + lum / (white * white)) / (1f32 + lum),
+		None => lum / (1f32 + lum),
 	};
 
-	let lum = (RED_LUM * pixel[0] as f32)
-		+ (GREEN_LUM * pixel[1] as f32)
-		+ (BLUE_LUM * pixel[2] as f32);
-	
-	if (lum as u8) < t { 1 } else { 0 }
+	let scale = mapped / lum;
+	image::Rgb([
+		((r * scale).clamp(0f32, 1f32) * 255f32).round() as u8,
+		((g * scale).clamp(0f32, 1f32) * 255f32).round() as u8,
+		((b * scale).clamp(0f32, 1f32) * 255f32).round() as u8,
+	])
 }
 
-fn to_braille(image: DynamicImage, t: u8, double: bool) -> String {
+fn decode_radiance_hdr(data: &[u8], white_point: Option<f32>) -> image::ImageResult<DynamicImage> {
+	let decoder = HdrDecoder::new(Cursor::new(data))?;
+	let meta = decoder.metadata();
+	let pixels = decoder.read_image_hdr()?;
+
+	let mut out = image::RgbImage::new(meta.width, meta.height);
+	for (pixel, mapped) in pixels.iter().zip(out.pixels_mut()) {
+		*mapped = tone_map_pixel(pixel[0], pixel[1], pixel[2], white_point);
+	}
+
+	Ok(DynamicImage::ImageRgb8(out))
+}
+
+fn luminance_buffer(image: &DynamicImage) -> (Vec<u8>, u32, u32) {
+	let (width, height) = image.dimensions();
+	let mut buf = vec![0u8; (width * height) as usize];
+
+	for y in 0..height {
+		for x in 0..width {
+			buf[(y * width + x) as usize] = pixel_brightness(image.get_pixel(x, y));
+		}
+	}
+
+	(buf, width, height)
+}
+
+fn pixel_brightness_at(buf: &[u8], width: u32, height: u32, x: u32, y: u32) -> u8 {
+	if x < width && y < height {
+		buf[(y * width + x) as usize]
+	} else {
+		pixel_brightness(Rgba([ 255, 255, 255, 0 ]))
+	}
+}
+
+fn is_dark(buf: &[u8], width: u32, height: u32, x: u32, y: u32, t: u8) -> usize {
+	if pixel_brightness_at(buf, width, height, x, y) < t { 1 } else { 0 }
+}
+
+fn cell_color(image: &DynamicImage, x: u32, y: u32, w: u32, h: u32) -> (u8, u8, u8) {
+	let (mut r, mut g, mut b) = (0u32, 0u32, 0u32);
+	let count = w * h;
+
+	for cy in 0..h {
+		for cx in 0..w {
+			let pixel = pixel_or_default(image, x + cx, y + cy);
+			r += pixel[0] as u32;
+			g += pixel[1] as u32;
+			b += pixel[2] as u32;
+		}
+	}
+
+	((r / count) as u8, (g / count) as u8, (b / count) as u8)
+}
+
+fn render_terminal(grid: &Grid, color: bool) -> String {
 	let mut out = String::new();
 
-	let ch = (image.height() as f32 / 4f32).ceil() as u32;
-	let cw = (image.width() as f32 / 2f32).ceil() as u32;
+	for row in grid {
+		let mut last_color = None;
+
+		for cell in row {
+			if color && last_color != cell.color {
+				if let Some((r, g, b)) = cell.color {
+					out.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b));
+				}
+				last_color = cell.color;
+			}
+
+			out.push(cell.ch);
+		}
+
+		if color { out.push_str(RESET_SGR); }
+		out.push('\n');
+	}
+
+	out
+}
+
+// Each row is the 3 columns' on/off bits, MSB first.
+fn ascii_font(ch: char) -> Option<[u8; 5]> {
+	match ch {
+		' ' => Some([ 0b000, 0b000, 0b000, 0b000, 0b000 ]),
+		'.' => Some([ 0b000, 0b000, 0b000, 0b000, 0b010 ]),
+		',' => Some([ 0b000, 0b000, 0b000, 0b010, 0b100 ]),
+		'-' => Some([ 0b000, 0b000, 0b111, 0b000, 0b000 ]),
+		'/' => Some([ 0b001, 0b001, 0b010, 0b100, 0b100 ]),
+		'O' => Some([ 0b111, 0b101, 0b101, 0b101, 0b111 ]),
+		'#' => Some([ 0b101, 0b111, 0b101, 0b111, 0b101 ]),
+		'@' => Some([ 0b111, 0b111, 0b111, 0b111, 0b111 ]),
+		_ => None,
+	}
+}
+
+fn fill_cell(image: &mut RgbaImage, origin_x: u32, origin_y: u32, cols: u32, rows: u32, ink: (u8, u8, u8), lit: impl Fn(u32, u32) -> bool) {
+	for dy in 0..CELL_HEIGHT {
+		for dx in 0..CELL_WIDTH {
+			let (gx, gy) = (dx * cols / CELL_WIDTH, dy * rows / CELL_HEIGHT);
+			if !lit(gx, gy) { continue; }
+
+			image.put_pixel(
+				origin_x + dx, origin_y + dy,
+				Rgba([ ink.0, ink.1, ink.2, 255 ]),
+			);
+		}
+	}
+}
+
+fn render_raster(grid: &Grid, background: (u8, u8, u8)) -> RgbaImage {
+	let rows = grid.len() as u32;
+	let cols = grid.first().map_or(0, |row| row.len()) as u32;
+
+	let mut image = RgbaImage::from_pixel(
+		cols * CELL_WIDTH, rows * CELL_HEIGHT,
+		Rgba([ background.0, background.1, background.2, 255 ]),
+	);
+
+	for (ry, row) in grid.iter().enumerate() {
+		for (cx, cell) in row.iter().enumerate() {
+			let ink = cell.color.unwrap_or(DEFAULT_INK);
+			let (origin_x, origin_y) = (cx as u32 * CELL_WIDTH, ry as u32 * CELL_HEIGHT);
+
+			if let Some(pattern) = &cell.dots {
+				fill_cell(&mut image, origin_x, origin_y, pattern.cols, pattern.rows, ink, |gx, gy| {
+					pattern.dots[(gy * pattern.cols + gx) as usize]
+				});
+			} else if let Some(font) = ascii_font(cell.ch) {
+				fill_cell(&mut image, origin_x, origin_y, 3, 5, ink, |gx, gy| {
+					font[gy as usize] & (0b100 >> gx) != 0
+				});
+			} else {
+				let threshold = (cell.brightness as u16 * 16 / 256) as u8;
+				fill_cell(&mut image, origin_x, origin_y, 4, 4, ink, |gx, gy| {
+					BAYER_4X4[gy as usize][gx as usize] < threshold
+				});
+			}
+		}
+	}
+
+	image
+}
+
+fn dither(luminance: &[u8], width: u32, height: u32, t: u8) -> Vec<u8> {
+	let (width, height) = (width as usize, height as usize);
+
+	let mut lum: Vec<f32> = luminance.iter().map(|&l| l as f32).collect();
+	let mut out = vec![0u8; width * height];
+
+	for y in 0..height {
+		for x in 0..width {
+			let idx = y * width + x;
+			let old = lum[idx];
+			let new = if old >= t as f32 { 255f32 } else { 0f32 };
+			let err = old - new;
+
+			out[idx] = if new == 0f32 { 1 } else { 0 };
+
+			let mut spread = |dx: isize, dy: isize, weight: f32| {
+				let (nx, ny) = (x as isize + dx, y as isize + dy);
+				if nx >= 0 && nx < width as isize && ny >= 0 && ny < height as isize {
+					lum[ny as usize * width + nx as usize] += err * weight;
+				}
+			};
+
+			spread(1, 0, 7f32 / 16f32);
+			spread(-1, 1, 3f32 / 16f32);
+			spread(0, 1, 5f32 / 16f32);
+			spread(1, 1, 1f32 / 16f32);
+		}
+	}
+
+	out
+}
+
+fn dither_dark_at(buf: &[u8], width: u32, height: u32, x: u32, y: u32) -> usize {
+	if x < width && y < height {
+		buf[(y * width + x) as usize] as usize
+	} else {
+		0
+	}
+}
+
+fn to_braille(buf: &ImageBuf, t: u8, double: bool, color: bool, dither_enabled: bool) -> Grid {
+	let &ImageBuf { image, luminance, width, height } = buf;
+	let mut grid = Grid::new();
+
+	let ch = (height as f32 / 4f32).ceil() as u32;
+	let cw = (width as f32 / 2f32).ceil() as u32;
+
+	let dithered = dither_enabled.then(|| dither(luminance, width, height, t));
 
 	for cy in 0..ch {
+		let mut row = Vec::new();
+
 		for cx in 0..cw {
 			let x = cx * 2;
 			let y = cy * 4;
 
-			let ch = BRAILLE
-					[is_dark(image.clone(), x + 0, y + 0, t)][is_dark(image.clone(), x + 1, y + 0, t)]
-					[is_dark(image.clone(), x + 0, y + 1, t)][is_dark(image.clone(), x + 1, y + 1, t)]
-					[is_dark(image.clone(), x + 0, y + 2, t)][is_dark(image.clone(), x + 1, y + 2, t)]
-					[is_dark(image.clone(), x + 0, y + 3, t)][is_dark(image.clone(), x + 1, y + 3, t)];
-			if double { out.push(ch); }
-			out.push(ch);
+			let dark = |dx: u32, dy: u32| match &dithered {
+				Some(buf) => dither_dark_at(buf, width, height, x + dx, y + dy),
+				None => is_dark(luminance, width, height, x + dx, y + dy, t),
+			};
+
+			let glyph = BRAILLE
+					[dark(0, 0)][dark(1, 0)]
+					[dark(0, 1)][dark(1, 1)]
+					[dark(0, 2)][dark(1, 2)]
+					[dark(0, 3)][dark(1, 3)];
+
+			let brightness = pixel_brightness_at(luminance, width, height, x, y);
+			let pixel_color = color.then(|| cell_color(image, x, y, 2, 4));
+			let dots = Some(DotPattern {
+				cols: 2,
+				rows: 4,
+				dots: (0..4).flat_map(|dy| (0..2).map(move |dx| (dx, dy)))
+					.map(|(dx, dy)| dark(dx, dy) != 0)
+					.collect(),
+			});
+
+			if double { row.push(Cell { ch: glyph, brightness, color: pixel_color, dots: dots.clone() }); }
+			row.push(Cell { ch: glyph, brightness, color: pixel_color, dots });
 		}
 
-		out.push('\n')
+		grid.push(row);
 	}
 
-	out
+	grid
 }
 
-fn to_blocks(image: DynamicImage, t: u8, double: bool) -> String {
-	let mut out = String::new();
+fn to_blocks(buf: &ImageBuf, t: u8, double: bool, color: bool, dither_enabled: bool) -> Grid {
+	let &ImageBuf { image, luminance, width, height } = buf;
+	let mut grid = Grid::new();
 
-	let ch = (image.height() as f32 / 2f32).ceil() as u32;
-	let cw = (image.width() as f32 / 2f32).ceil() as u32;
+	let ch = (height as f32 / 2f32).ceil() as u32;
+	let cw = (width as f32 / 2f32).ceil() as u32;
+
+	let dithered = dither_enabled.then(|| dither(luminance, width, height, t));
 
 	for cy in 0..ch {
+		let mut row = Vec::new();
+
 		for cx in 0..cw {
 			let x = cx * 2;
 			let y = cy * 2;
 
-			let ch = BOX
-					[is_dark(image.clone(), x + 0, y + 0, t)][is_dark(image.clone(), x + 1, y + 0, t)]
-					[is_dark(image.clone(), x + 0, y + 1, t)][is_dark(image.clone(), x + 1, y + 1, t)];
-			if double { out.push(ch); }
-			out.push(ch);
+			let dark = |dx: u32, dy: u32| match &dithered {
+				Some(buf) => dither_dark_at(buf, width, height, x + dx, y + dy),
+				None => is_dark(luminance, width, height, x + dx, y + dy, t),
+			};
+
+			let glyph = BOX
+					[dark(0, 0)][dark(1, 0)]
+					[dark(0, 1)][dark(1, 1)];
+
+			let brightness = pixel_brightness_at(luminance, width, height, x, y);
+			let pixel_color = color.then(|| cell_color(image, x, y, 2, 2));
+			let dots = Some(DotPattern {
+				cols: 2,
+				rows: 2,
+				dots: (0..2).flat_map(|dy| (0..2).map(move |dx| (dx, dy)))
+					.map(|(dx, dy)| dark(dx, dy) != 0)
+					.collect(),
+			});
+
+			if double { row.push(Cell { ch: glyph, brightness, color: pixel_color, dots: dots.clone() }); }
+			row.push(Cell { ch: glyph, brightness, color: pixel_color, dots });
 		}
 
-		out.push('\n')
+		grid.push(row);
 	}
 
-	out
+	grid
 }
 
-fn to_ascii(image: DynamicImage, double: bool) -> String {
-	let mut out = String::new();
+fn ramp_index(brightness: u8, ramp_len: usize, gamma: f32) -> usize {
+	let normalised = (brightness as f32 / 255.0).powf(gamma);
+	(normalised * (ramp_len - 1) as f32).round() as usize
+}
 
-	for y in 0..image.height() {
-		for x in 0..image.width() {
-			let brightness = pixel_brightness(image.get_pixel(x, y));
+// Derived from out_height itself so every row in 0..out_height gets a non-empty range.
+fn aspect_row_range(out_y: u32, out_height: u32, height: u32) -> (u32, u32) {
+	let start = (out_y as u64 * height as u64 / out_height as u64) as u32;
+	let end = ((out_y as u64 + 1) * height as u64 / out_height as u64) as u32;
 
-			let ch = ASCII_CHARS[(brightness / 32) as usize];
-			if double { out.push(ch); }
-			out.push(ch);
+	(start, end.max(start + 1).min(height))
+}
+
+fn to_ascii(buf: &ImageBuf, double: bool, color: bool, charset: &[char], gamma: f32, aspect: f32) -> Grid {
+	let &ImageBuf { image, luminance, width, height } = buf;
+	let mut grid = Grid::new();
+
+	let out_height = ((height as f32) * aspect).ceil().max(1f32) as u32;
+
+	for out_y in 0..out_height {
+		let (y_start, y_end) = aspect_row_range(out_y, out_height, height);
+		let mut row = Vec::new();
+
+		for x in 0..width {
+			let mut brightness_sum = 0u32;
+			let (mut r_sum, mut g_sum, mut b_sum) = (0u32, 0u32, 0u32);
+			let rows = y_end - y_start;
+
+			for y in y_start..y_end {
+				brightness_sum += pixel_brightness_at(luminance, width, height, x, y) as u32;
+				if color {
+					let pixel = image.get_pixel(x, y);
+					r_sum += pixel[0] as u32;
+					g_sum += pixel[1] as u32;
+					b_sum += pixel[2] as u32;
+				}
+			}
+
+			let brightness = (brightness_sum / rows) as u8;
+			let glyph = charset[ramp_index(brightness, charset.len(), gamma)];
+			let pixel_color = color.then(|| (
+				(r_sum / rows) as u8,
+				(g_sum / rows) as u8,
+				(b_sum / rows) as u8,
+			));
+
+			if double { row.push(Cell { ch: glyph, brightness, color: pixel_color, dots: None }); }
+			row.push(Cell { ch: glyph, brightness, color: pixel_color, dots: None });
 		}
 
-		out.push('\n');
+		grid.push(row);
 	}
 
-	out
+	grid
 }